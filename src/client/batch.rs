@@ -0,0 +1,235 @@
+use crate::{models::{create, BucketAccessControl, Entity, Response}, Error};
+
+/// The Cloud Storage JSON API batch endpoint.
+const BATCH_URL: &str = "https://storage.googleapis.com/batch/storage/v1";
+
+/// The documented maximum number of sub-operations allowed in a single batch request. Larger
+/// queues are split into several requests automatically.
+const MAX_BATCH_SIZE: usize = 100;
+
+/// The multipart boundary used for the request body.
+const BOUNDARY: &str = "===============cloud_storage_rs_batch==";
+
+/// A single queued ACL mutation.
+enum Op {
+    Create(create::BucketAccessControl),
+    Update(BucketAccessControl),
+    Delete(Entity),
+}
+
+impl Op {
+    /// The HTTP method and request body for this sub-operation, relative to `acl_path`. Serializing
+    /// the JSON body is fallible; a failure is surfaced rather than silently sending an empty body.
+    fn render(&self, acl_path: &str) -> Result<String, Error> {
+        Ok(match self {
+            Op::Create(body) => {
+                let json = serde_json::to_string(body)?;
+                format!("POST {} HTTP/1.1\r\nContent-Type: application/json\r\n\r\n{}", acl_path, json)
+            }
+            Op::Update(body) => {
+                let json = serde_json::to_string(body)?;
+                let path = format!("{}/{}", acl_path, crate::percent_encode(&body.entity.to_string()));
+                format!("PUT {} HTTP/1.1\r\nContent-Type: application/json\r\n\r\n{}", path, json)
+            }
+            Op::Delete(entity) => {
+                let path = format!("{}/{}", acl_path, crate::percent_encode(&entity.to_string()));
+                format!("DELETE {} HTTP/1.1\r\n\r\n", path)
+            }
+        })
+    }
+}
+
+/// Queues several ACL mutations and dispatches them in a single `multipart/mixed` request to the
+/// GCS batch endpoint, rather than one round-trip (and one `get_headers()` call) per mutation.
+///
+/// Queues larger than the 100 sub-operation limit are split into several requests transparently.
+#[derive(Debug)]
+pub struct BatchBuilder<'a> {
+    pub(crate) client: &'a super::client::Client,
+    /// The absolute-path portion of the ACL collection URL, e.g. `/storage/v1/b/my_bucket/acl`.
+    pub(crate) acl_path: String,
+    ops: Vec<Op>,
+}
+
+impl<'a> BatchBuilder<'a> {
+    pub(crate) fn new(client: &'a super::client::Client, acl_path: String) -> Self {
+        Self { client, acl_path, ops: Vec::new() }
+    }
+
+    /// Queue the creation of a new `BucketAccessControl`.
+    pub fn create_using(&mut self, new_bucket_access_control: &create::BucketAccessControl) -> &mut Self {
+        self.ops.push(Op::Create(new_bucket_access_control.clone()));
+        self
+    }
+
+    /// Queue an update to an existing `BucketAccessControl`.
+    pub fn update(&mut self, bucket_access_control: &BucketAccessControl) -> &mut Self {
+        self.ops.push(Op::Update(bucket_access_control.clone()));
+        self
+    }
+
+    /// Queue the deletion of the ACL entry for `entity`.
+    pub fn delete(&mut self, entity: &Entity) -> &mut Self {
+        self.ops.push(Op::Delete(entity.clone()));
+        self
+    }
+
+    /// Dispatch every queued operation, splitting into chunks of at most 100 as needed, and return
+    /// a result per operation aligned to the order they were queued.
+    ///
+    /// A successful `DELETE` returns no body and so maps to `Ok(None)`; a successful create or
+    /// update maps to `Ok(Some(_))` with the resulting [`BucketAccessControl`](BucketAccessControl).
+    pub async fn send(self) -> Result<Vec<Result<Option<BucketAccessControl>, Error>>, Error> {
+        let mut results = Vec::with_capacity(self.ops.len());
+        for chunk in self.ops.chunks(MAX_BATCH_SIZE) {
+            let body = self.serialize_chunk(chunk)?;
+            let headers = self.client.get_headers().await?;
+            let response = self
+                .client
+                .reqwest
+                .post(BATCH_URL)
+                .header(reqwest::header::CONTENT_TYPE, format!("multipart/mixed; boundary={}", BOUNDARY))
+                .headers(headers)
+                .body(body)
+                .send()
+                .await?;
+            let boundary = response_boundary(&response);
+            let text = response.text().await?;
+            results.extend(parse_multipart_response(&text, boundary.as_deref(), chunk.len()));
+        }
+        Ok(results)
+    }
+
+    /// Serialize a chunk of operations as a `multipart/mixed` body, one `application/http` part per
+    /// sub-operation with a `Content-ID` carrying its 1-based index within the chunk.
+    fn serialize_chunk(&self, chunk: &[Op]) -> Result<String, Error> {
+        let mut body = String::new();
+        for (index, op) in chunk.iter().enumerate() {
+            body.push_str(&format!("--{}\r\n", BOUNDARY));
+            body.push_str("Content-Type: application/http\r\n");
+            body.push_str(&format!("Content-ID: <item-{}>\r\n\r\n", index + 1));
+            body.push_str(&op.render(&self.acl_path)?);
+            body.push_str("\r\n");
+        }
+        body.push_str(&format!("--{}--\r\n", BOUNDARY));
+        Ok(body)
+    }
+}
+
+/// Extract the multipart boundary from a response's `Content-Type` header, e.g.
+/// `multipart/mixed; boundary=batch_abc` yields `batch_abc`.
+fn response_boundary(response: &reqwest::Response) -> Option<String> {
+    let content_type = response.headers().get(reqwest::header::CONTENT_TYPE)?.to_str().ok()?;
+    let boundary = content_type.split("boundary=").nth(1)?.trim();
+    Some(boundary.trim_matches('"').to_string())
+}
+
+/// Parse a `multipart/mixed` batch response body into one result per sub-operation. Parts are split
+/// on the response's own `boundary` and reordered by the `Content-ID` (`<response-item-N>`) the
+/// server echoes back, so the returned vector stays aligned to the queued order regardless of the
+/// order the parts arrive in. `expected` is the number of operations in the chunk; missing slots
+/// are filled with an error.
+fn parse_multipart_response(
+    body: &str,
+    boundary: Option<&str>,
+    expected: usize,
+) -> Vec<Result<Option<BucketAccessControl>, Error>> {
+    let delimiter = boundary.map(|b| format!("--{}", b)).unwrap_or_else(|| format!("--{}", BOUNDARY));
+    let mut by_index: Vec<Option<Result<Option<BucketAccessControl>, Error>>> =
+        (0..expected).map(|_| None).collect();
+    for part in body.split(delimiter.as_str()) {
+        let part = part.trim_start_matches("\r\n").trim_start_matches('\n');
+        if part.is_empty() || part.starts_with("--") {
+            continue;
+        }
+        let index = match content_id_index(part) {
+            Some(i) if i >= 1 && i <= expected => i - 1,
+            _ => continue,
+        };
+        // The part headers, the embedded response status/headers, and the embedded body are each
+        // separated by a blank line.
+        let payload = part.splitn(3, "\r\n\r\n").nth(2).unwrap_or("").trim();
+        by_index[index] = Some(parse_embedded_body(payload));
+    }
+    by_index
+        .into_iter()
+        .map(|slot| slot.unwrap_or_else(|| Err(Error::Other("batch response missing a sub-response".to_string()))))
+        .collect()
+}
+
+/// Read the 1-based index `N` out of a part's `Content-ID: <response-item-N>` header.
+fn content_id_index(part: &str) -> Option<usize> {
+    let line = part.lines().find(|l| l.to_ascii_lowercase().starts_with("content-id:"))?;
+    let id = line.splitn(2, ':').nth(1)?.trim().trim_matches(|c| c == '<' || c == '>');
+    id.rsplit('-').next()?.parse().ok()
+}
+
+/// Interpret the JSON body of a single embedded sub-response. An empty body (as returned by a
+/// successful `DELETE`) maps to `Ok(None)`; otherwise a Google error payload surfaces as
+/// [`Error::Google`](crate::Error::Google) via the standard [`Response`](Response) wrapper, and a
+/// success body is returned as `Ok(Some(_))`.
+fn parse_embedded_body(payload: &str) -> Result<Option<BucketAccessControl>, Error> {
+    if payload.is_empty() {
+        return Ok(None);
+    }
+    let parsed: Response<BucketAccessControl> = serde_json::from_str(payload)?;
+    Ok(Some(parsed?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Assemble one `application/http` part embedding a sub-response with the given status and body.
+    fn part(boundary: &str, content_id: usize, status: &str, body: &str) -> String {
+        format!(
+            "--{}\r\nContent-Type: application/http\r\nContent-ID: <response-item-{}>\r\n\r\nHTTP/1.1 {}\r\nContent-Type: application/json; charset=UTF-8\r\n\r\n{}\r\n",
+            boundary, content_id, status, body,
+        )
+    }
+
+    #[test]
+    fn content_id_index_reads_the_item_number() {
+        assert_eq!(content_id_index("Content-ID: <response-item-3>\r\n\r\nbody"), Some(3));
+        assert_eq!(content_id_index("content-id: <item-7>"), Some(7));
+        assert_eq!(content_id_index("Content-Type: application/http"), None);
+    }
+
+    #[test]
+    fn empty_body_is_a_successful_delete() {
+        assert!(matches!(parse_embedded_body(""), Ok(None)));
+    }
+
+    #[test]
+    fn reorders_parts_by_content_id_and_aligns_to_queued_order() {
+        let boundary = "batch_boundary";
+        let error = r#"{"error":{"code":404,"message":"Not Found","errors":[]}}"#;
+        // Parts arrive out of order: item 3 (deleted), item 1 (error), item 2 (deleted).
+        let body = format!(
+            "{p3}{p1}{p2}--{b}--\r\n",
+            b = boundary,
+            p3 = part(boundary, 3, "204 No Content", ""),
+            p1 = part(boundary, 1, "404 Not Found", error),
+            p2 = part(boundary, 2, "204 No Content", ""),
+        );
+
+        let results = parse_multipart_response(&body, Some(boundary), 3);
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_err(), "item 1 was an error sub-response");
+        assert!(matches!(results[1], Ok(None)), "item 2 was a successful delete");
+        assert!(matches!(results[2], Ok(None)), "item 3 was a successful delete");
+    }
+
+    #[test]
+    fn missing_parts_are_filled_with_an_error() {
+        let boundary = "batch_boundary";
+        let body = format!("{p1}--{b}--\r\n", b = boundary, p1 = part(boundary, 1, "204 No Content", ""));
+
+        let results = parse_multipart_response(&body, Some(boundary), 2);
+
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[0], Ok(None)));
+        assert!(results[1].is_err(), "the missing second slot is an error");
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{models::{create, BucketAccessControl, ListResponse, Entity, Response}, Error};
+use crate::{models::{create, Bucket, BucketAccessControl, ListResponse, Entity, PredefinedBucketAcl, Response}, Error};
 
 /// Operations on [`BucketAccessControl`](BucketAccessControl)s.
 #[derive(Debug)]
@@ -40,6 +40,89 @@ impl<'a> BucketAccessControlClient<'a> {
         Ok(result?)
     }
 
+    /// Returns an [`IamPolicyClient`](super::iam_policy::IamPolicyClient) for this bucket's IAM
+    /// policy, which is the sanctioned way to control access for buckets with uniform bucket-level
+    /// access enabled.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let policy = client.bucket_access_control("my_bucket").iam().get_iam_policy().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iam(&self) -> super::iam_policy::IamPolicyClient<'a> {
+        let iam_url = format!("{}/iam", self.bucket_acl_url.trim_end_matches("/acl"));
+        super::iam_policy::IamPolicyClient { client: self.client, iam_url }
+    }
+
+    /// Returns a [`BatchBuilder`](super::batch::BatchBuilder) that queues several ACL mutations and
+    /// dispatches them in a single request to the GCS batch endpoint.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::bucket_access_control::{create, Entity, Role};
+    ///
+    /// let client = Client::default();
+    /// let mut batch = client.bucket_access_control("my_bucket").batch();
+    /// batch
+    ///     .create_using(&create::BucketAccessControl { entity: Entity::AllUsers, role: Role::Reader })
+    ///     .delete(&Entity::AllAuthenticatedUsers);
+    /// let results = batch.send().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn batch(&self) -> super::batch::BatchBuilder<'a> {
+        let acl_path = self
+            .bucket_acl_url
+            .find("/storage/")
+            .map(|i| self.bucket_acl_url[i..].to_string())
+            .unwrap_or_else(|| self.bucket_acl_url.clone());
+        super::batch::BatchBuilder::new(self.client, acl_path)
+    }
+
+    /// Applies a predefined (canned) ACL template to this bucket in a single request, flipping it
+    /// to a standard access template without the `list`/`delete`/`create_using` loop.
+    ///
+    /// This issues a bucket `PATCH` with the `predefinedAcl` query parameter and returns the
+    /// updated [`Bucket`](Bucket).
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    /// use cloud_storage::bucket_access_control::PredefinedBucketAcl;
+    ///
+    /// let client = Client::default();
+    /// client.bucket_access_control("my_bucket").set_predefined(PredefinedBucketAcl::PublicRead).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_predefined(&self, acl: PredefinedBucketAcl) -> Result<Bucket, Error> {
+        let bucket_url = self.bucket_acl_url.trim_end_matches("/acl");
+        let headers = self.client.get_headers().await?;
+        let result: crate::models::Response<Bucket> = self
+            .client
+            .reqwest
+            .patch(bucket_url)
+            .query(&[("predefinedAcl", acl.to_string())])
+            .headers(headers)
+            .json(&serde_json::json!({}))
+            .send()
+            .await?
+            .json()
+            .await?;
+        Ok(result?)
+    }
+
     /// Returns all `BucketAccessControl`s related to this bucket.
     ///
     /// ### Important
@@ -0,0 +1,93 @@
+use crate::{models::{Policy, Response, TestIamPermissionsResponse}, Error};
+
+/// Operations on the IAM [`Policy`](Policy) of a bucket.
+///
+/// This is the sanctioned way to control access for buckets with uniform bucket-level access
+/// enabled, for which the per-entity [`BucketAccessControl`](crate::bucket_access_control::BucketAccessControl)
+/// methods fail with a 400 Bad Request.
+#[derive(Debug)]
+pub struct IamPolicyClient<'a> {
+    pub(crate) client: &'a super::client::Client,
+    pub(crate) iam_url: String,
+}
+
+impl<'a> IamPolicyClient<'a> {
+    /// Returns the IAM policy currently in effect for this bucket.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let policy = client.bucket_access_control("my_bucket").iam().get_iam_policy().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_iam_policy(&self) -> Result<Policy, Error> {
+        let headers = self.client.get_headers().await?;
+        let result: Response<Policy> = self
+            .client
+            .reqwest
+            .get(&self.iam_url)
+            .query(&[("optionsRequestedPolicyVersion", "3")])
+            .headers(headers)
+            .send()
+            .await?
+            .json()
+            .await?;
+        Ok(result?)
+    }
+
+    /// Updates the IAM policy for this bucket, replacing any existing policy.
+    ///
+    /// The `policy` must carry the `etag` returned by [`get_iam_policy`](Self::get_iam_policy) so
+    /// that a conflicting concurrent write fails with a 409 rather than clobbering the other
+    /// caller's change.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let mut policy = client.bucket_access_control("my_bucket").iam().get_iam_policy().await?;
+    /// policy.bindings.clear();
+    /// client.bucket_access_control("my_bucket").iam().set_iam_policy(&policy).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_iam_policy(&self, policy: &Policy) -> Result<Policy, Error> {
+        let headers = self.client.get_headers().await?;
+        let result: Response<Policy> = self.client.reqwest.put(&self.iam_url).headers(headers).json(policy).send().await?.json().await?;
+        Ok(result?)
+    }
+
+    /// Tests which of the given `permissions` the caller holds on this bucket, returning the subset
+    /// that are granted.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use cloud_storage::Client;
+    ///
+    /// let client = Client::default();
+    /// let granted = client
+    ///     .bucket_access_control("my_bucket")
+    ///     .iam()
+    ///     .test_iam_permissions(&["storage.buckets.get"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn test_iam_permissions(&self, permissions: &[&str]) -> Result<Vec<String>, Error> {
+        let url = format!("{}/testPermissions", self.iam_url);
+        let query: Vec<(&str, &str)> = permissions.iter().map(|p| ("permissions", *p)).collect();
+        let headers = self.client.get_headers().await?;
+        let result: Response<TestIamPermissionsResponse> = self.client.reqwest.get(&url).query(&query).headers(headers).send().await?.json().await?;
+        Ok(result?.permissions)
+    }
+}
@@ -0,0 +1,274 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::Error;
+
+/// An entity is used to represent a user or group of users that often have some kind of permission
+/// against a bucket or object.
+///
+/// Every `Entity` has a single canonical [`Display`] form in the string grammar used by the Cloud
+/// Storage API, and [`Entity::parse`] (and the [`FromStr`] impl) reconstructs the same value from
+/// that form provided its values are non-empty, `*Email` values contain `@` and `*Id` values do
+/// not. The `user_*` and `group_*` constructors check only the `@` rule; no constructor rejects an
+/// empty value, so for example `Entity::domain("")` displays as `domain-`, which does not parse.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Entity {
+    /// Matches the id of a user.
+    UserId(String),
+    /// Matches the email of a user.
+    UserEmail(String),
+    /// Matches the id of a group.
+    GroupId(String),
+    /// Matches the email of a group.
+    GroupEmail(String),
+    /// Matches all users of a domain name, for example `example.com`.
+    Domain(String),
+    /// Matches a project team, identified by a [`Team`] role and a project id.
+    Project(Team, String),
+    /// Matches all users.
+    AllUsers,
+    /// Matches all authenticated users.
+    AllAuthenticatedUsers,
+}
+
+/// The role a [`Entity::Project`] member holds within its project.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Team {
+    /// The team of project owners.
+    Owners,
+    /// The team of project editors.
+    Editors,
+    /// The team of project viewers.
+    Viewers,
+}
+
+impl Entity {
+    /// Construct an `Entity` matching a user by email address.
+    ///
+    /// Because [`FromStr`] infers the email/id split from the presence of `@`, the address must
+    /// contain one for the value to round-trip; a missing `@` is rejected here rather than silently
+    /// parsing back as a [`UserId`](Entity::UserId).
+    pub fn user_email(email: impl Into<String>) -> Result<Self, Error> {
+        let email = email.into();
+        require_email(&email)?;
+        Ok(Entity::UserEmail(email))
+    }
+
+    /// Construct an `Entity` matching a user by id.
+    ///
+    /// The id must not contain `@`, which would otherwise round-trip back as a
+    /// [`UserEmail`](Entity::UserEmail).
+    pub fn user_id(id: impl Into<String>) -> Result<Self, Error> {
+        let id = id.into();
+        require_id(&id)?;
+        Ok(Entity::UserId(id))
+    }
+
+    /// Construct an `Entity` matching a group by email address.
+    ///
+    /// As with [`user_email`](Self::user_email), the address must contain `@` to round-trip.
+    pub fn group_email(email: impl Into<String>) -> Result<Self, Error> {
+        let email = email.into();
+        require_email(&email)?;
+        Ok(Entity::GroupEmail(email))
+    }
+
+    /// Construct an `Entity` matching a group by id.
+    ///
+    /// The id must not contain `@`, which would otherwise round-trip back as a
+    /// [`GroupEmail`](Entity::GroupEmail).
+    pub fn group_id(id: impl Into<String>) -> Result<Self, Error> {
+        let id = id.into();
+        require_id(&id)?;
+        Ok(Entity::GroupId(id))
+    }
+
+    /// Construct an `Entity` matching all users of a domain name.
+    pub fn domain(domain: impl Into<String>) -> Self {
+        Entity::Domain(domain.into())
+    }
+
+    /// Construct an `Entity` matching a project team.
+    pub fn project_team(team: Team, project_id: impl Into<String>) -> Self {
+        Entity::Project(team, project_id.into())
+    }
+
+    /// Parse an `Entity` from its canonical string form, rejecting malformed input before it
+    /// reaches the network (which would otherwise return an opaque 400 Bad Request).
+    pub fn parse(s: &str) -> Result<Entity, Error> {
+        s.parse()
+    }
+}
+
+/// An email-shaped entity value must contain an `@` so that it round-trips through [`FromStr`].
+fn require_email(value: &str) -> Result<(), Error> {
+    if value.contains('@') {
+        Ok(())
+    } else {
+        Err(Error::Other(format!("ACL entity email must contain '@': {:?}", value)))
+    }
+}
+
+/// An id-shaped entity value must not contain an `@`, which would round-trip back as an email.
+fn require_id(value: &str) -> Result<(), Error> {
+    if value.contains('@') {
+        Err(Error::Other(format!("ACL entity id must not contain '@': {:?}", value)))
+    } else {
+        Ok(())
+    }
+}
+
+impl Display for Entity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Entity::UserId(s) => write!(f, "user-{}", s),
+            Entity::UserEmail(s) => write!(f, "user-{}", s),
+            Entity::GroupId(s) => write!(f, "group-{}", s),
+            Entity::GroupEmail(s) => write!(f, "group-{}", s),
+            Entity::Domain(s) => write!(f, "domain-{}", s),
+            Entity::Project(team, project_id) => write!(f, "project-{}-{}", team, project_id),
+            Entity::AllUsers => write!(f, "allUsers"),
+            Entity::AllAuthenticatedUsers => write!(f, "allAuthenticatedUsers"),
+        }
+    }
+}
+
+impl FromStr for Entity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // An email address contains an `@`, which is how the API distinguishes the `*-email` forms
+        // from the `*-id` forms that share a prefix.
+        let malformed = || Error::Other(format!("malformed ACL entity: {:?}", s));
+        match s {
+            "allUsers" => Ok(Entity::AllUsers),
+            "allAuthenticatedUsers" => Ok(Entity::AllAuthenticatedUsers),
+            _ if s.starts_with("user-") => {
+                let rest = &s["user-".len()..];
+                if rest.is_empty() {
+                    Err(malformed())
+                } else if rest.contains('@') {
+                    Ok(Entity::UserEmail(rest.to_string()))
+                } else {
+                    Ok(Entity::UserId(rest.to_string()))
+                }
+            }
+            _ if s.starts_with("group-") => {
+                let rest = &s["group-".len()..];
+                if rest.is_empty() {
+                    Err(malformed())
+                } else if rest.contains('@') {
+                    Ok(Entity::GroupEmail(rest.to_string()))
+                } else {
+                    Ok(Entity::GroupId(rest.to_string()))
+                }
+            }
+            _ if s.starts_with("domain-") => {
+                let rest = &s["domain-".len()..];
+                if rest.is_empty() {
+                    Err(malformed())
+                } else {
+                    Ok(Entity::Domain(rest.to_string()))
+                }
+            }
+            _ if s.starts_with("project-") => {
+                let rest = &s["project-".len()..];
+                let (team, project_id) = rest.split_once('-').ok_or_else(malformed)?;
+                if project_id.is_empty() {
+                    return Err(malformed());
+                }
+                Ok(Entity::Project(team.parse()?, project_id.to_string()))
+            }
+            _ => Err(malformed()),
+        }
+    }
+}
+
+impl Display for Team {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Team::Owners => write!(f, "owners"),
+            Team::Editors => write!(f, "editors"),
+            Team::Viewers => write!(f, "viewers"),
+        }
+    }
+}
+
+impl FromStr for Team {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "owners" => Ok(Team::Owners),
+            "editors" => Ok(Team::Editors),
+            "viewers" => Ok(Team::Viewers),
+            other => Err(Error::Other(format!("unknown project team: {:?}", other))),
+        }
+    }
+}
+
+impl serde::Serialize for Entity {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Entity {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_every_variant() {
+        let entities = [
+            Entity::UserId("12345".to_string()),
+            Entity::UserEmail("alice@example.com".to_string()),
+            Entity::GroupId("67890".to_string()),
+            Entity::GroupEmail("team@example.com".to_string()),
+            Entity::Domain("example.com".to_string()),
+            Entity::Project(Team::Owners, "my-project".to_string()),
+            Entity::Project(Team::Editors, "my-project".to_string()),
+            Entity::Project(Team::Viewers, "my-project".to_string()),
+            Entity::AllUsers,
+            Entity::AllAuthenticatedUsers,
+        ];
+        for entity in entities {
+            assert_eq!(Entity::parse(&entity.to_string()).unwrap(), entity);
+        }
+    }
+
+    #[test]
+    fn disambiguates_email_and_id_by_at_sign() {
+        assert_eq!(Entity::parse("user-alice@example.com").unwrap(), Entity::UserEmail("alice@example.com".to_string()));
+        assert_eq!(Entity::parse("user-12345").unwrap(), Entity::UserId("12345".to_string()));
+        assert_eq!(Entity::parse("group-team@example.com").unwrap(), Entity::GroupEmail("team@example.com".to_string()));
+        assert_eq!(Entity::parse("group-67890").unwrap(), Entity::GroupId("67890".to_string()));
+    }
+
+    #[test]
+    fn constructors_validate_at_sign() {
+        assert!(Entity::user_email("alice@example.com").is_ok());
+        assert!(Entity::user_email("svc").is_err());
+        assert!(Entity::group_email("svc").is_err());
+        assert!(Entity::user_id("12345").is_ok());
+        assert!(Entity::user_id("alice@example.com").is_err());
+        assert!(Entity::group_id("team@example.com").is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_entities() {
+        assert!(Entity::parse("user-").is_err());
+        assert!(Entity::parse("group-").is_err());
+        assert!(Entity::parse("domain-").is_err());
+        assert!(Entity::parse("banana-1").is_err());
+        assert!(Entity::parse("project-owners").is_err());
+        assert!(Entity::parse("project-owners-").is_err());
+        assert!(Entity::parse("project-strangers-p1").is_err());
+    }
+}
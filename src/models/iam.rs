@@ -0,0 +1,65 @@
+/// An Identity and Access Management (IAM) policy, which specifies access controls for Google Cloud
+/// resources.
+///
+/// A `Policy` is a collection of `Binding`s. A `Binding` binds one or more `members` to a single
+/// `role`. Callers performing a read-modify-write must send back the `etag` they received so that
+/// conflicting writes fail with a 409 rather than silently clobbering each other.
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Policy {
+    /// The kind of resource this is, for Google Cloud Storage this is always
+    /// `storage#policy`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// The ID of the resource to which this policy belongs. Populated by the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_id: Option<String>,
+    /// The IAM policy format version.
+    pub version: i32,
+    /// The associations between a role and one or more members.
+    pub bindings: Vec<Binding>,
+    /// HTTP 1.1 entity tag for the policy. Pass this back unchanged on a `set` to guard against
+    /// concurrent modification.
+    pub etag: String,
+}
+
+/// An association between a `role` and the list of `members` that are granted it, optionally
+/// restricted by an IAM Condition.
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Binding {
+    /// The role that is assigned to `members`, for example `roles/storage.objectViewer`.
+    pub role: String,
+    /// The identities requesting access, for example `user:alice@example.com` or `allUsers`.
+    pub members: Vec<String>,
+    /// The condition under which this binding applies, supporting IAM Conditions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<Expr>,
+}
+
+/// A Common Expression Language (CEL) expression describing an IAM Condition.
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Expr {
+    /// Textual representation of the expression in CEL syntax.
+    pub expression: String,
+    /// An optional title for the expression, i.e. a short string describing its purpose.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// An optional description of the expression, a longer text which describes the expression.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// The response returned by `IamPolicyClient::test_iam_permissions`, listing the subset of the
+/// requested permissions that the caller actually holds.
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TestIamPermissionsResponse {
+    /// The kind of resource this is, always `storage#testIamPermissionsResponse`.
+    #[serde(default)]
+    pub kind: Option<String>,
+    /// The permissions held by the caller out of those requested.
+    #[serde(default)]
+    pub permissions: Vec<String>,
+}
@@ -0,0 +1,39 @@
+use std::fmt::{self, Display};
+
+/// A predefined (canned) ACL that can be applied to a bucket in a single request, rather than
+/// building up individual [`BucketAccessControl`](crate::bucket_access_control::BucketAccessControl)
+/// entries.
+///
+/// These map onto the `predefinedAcl`/`predefinedDefaultObjectAcl` query parameters of the Cloud
+/// Storage JSON API.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PredefinedBucketAcl {
+    /// Project team owners get `OWNER` access.
+    Private,
+    /// Project team members get access according to their roles.
+    ProjectPrivate,
+    /// Project team owners get `OWNER` access, and `allUsers` get `READER` access.
+    PublicRead,
+    /// Project team owners get `OWNER` access, and `allUsers` get `WRITER` access.
+    PublicReadWrite,
+    /// Project team owners get `OWNER` access, and `allAuthenticatedUsers` get `READER` access.
+    AuthenticatedRead,
+    /// Object and bucket owners get `OWNER` access, and project team owners get `READER` access.
+    BucketOwnerRead,
+    /// Object and bucket owners get `OWNER` access, and project team owners get `OWNER` access.
+    BucketOwnerFullControl,
+}
+
+impl Display for PredefinedBucketAcl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PredefinedBucketAcl::Private => write!(f, "private"),
+            PredefinedBucketAcl::ProjectPrivate => write!(f, "projectPrivate"),
+            PredefinedBucketAcl::PublicRead => write!(f, "publicRead"),
+            PredefinedBucketAcl::PublicReadWrite => write!(f, "publicReadWrite"),
+            PredefinedBucketAcl::AuthenticatedRead => write!(f, "authenticatedRead"),
+            PredefinedBucketAcl::BucketOwnerRead => write!(f, "bucketOwnerRead"),
+            PredefinedBucketAcl::BucketOwnerFullControl => write!(f, "bucketOwnerFullControl"),
+        }
+    }
+}